@@ -1,5 +1,6 @@
 use argh::FromArgs;
 use std::path::PathBuf;
+use uuid::Uuid;
 
 mod messages;
 
@@ -28,6 +29,7 @@ struct ClientArgs {
 enum ClientCommands {
     Inference(InferenceCommand),
     Results(ResultsCommand),
+    Jobs(JobsCommand),
 }
 
 #[derive(FromArgs)]
@@ -44,9 +46,18 @@ struct InferenceCommand {
 }
 
 #[derive(FromArgs)]
-/// Check inference results
+/// Check the result of a previously scheduled job
 #[argh(subcommand, name = "results")]
-struct ResultsCommand {}
+struct ResultsCommand {
+    /// the job id returned when the inference was scheduled
+    #[argh(option, short = 'j')]
+    job_id: Uuid,
+}
+
+#[derive(FromArgs)]
+/// List outstanding jobs and their status
+#[argh(subcommand, name = "jobs")]
+struct JobsCommand {}
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -71,15 +82,24 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             let result = response.json::<serde_json::Value>().await?;
             println!("Result: {}", serde_json::to_string_pretty(&result)?);
         }
-        ClientCommands::Results(_) => {
+        ClientCommands::Results(results_command) => {
             let response = client
-                .get(format!("http://{}/results", addr))
+                .get(format!(
+                    "http://{}/results/{}",
+                    addr, results_command.job_id
+                ))
                 .send()
                 .await?;
 
             let result = response.json::<serde_json::Value>().await?;
             println!("Result: {}", serde_json::to_string_pretty(&result)?);
         }
+        ClientCommands::Jobs(_) => {
+            let response = client.get(format!("http://{}/jobs", addr)).send().await?;
+
+            let result = response.json::<serde_json::Value>().await?;
+            println!("Jobs: {}", serde_json::to_string_pretty(&result)?);
+        }
     }
 
     Ok(())
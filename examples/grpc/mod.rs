@@ -0,0 +1,159 @@
+//! gRPC serving interface, run alongside the HTTP API on a separate port.
+//!
+//! Unlike `POST /inference`, which takes an `image_path` and therefore
+//! assumes client and server share a filesystem, requests here carry the
+//! image as raw bytes plus its dimensions, so a remote client can submit
+//! pixel data directly.
+
+use crate::{PaligemmaModel, PaligemmaRequest};
+use kornia_image::{Image, ImageSize, allocator::CpuAllocator};
+use kornia_infernum::{InfernumEngine, InfernumEngineResult, ScheduleError};
+use std::{pin::Pin, sync::Arc, time::Duration};
+use tokio_stream::{Stream, wrappers::UnboundedReceiverStream};
+use tonic::{Request, Response, Status};
+
+pub mod proto {
+    tonic::include_proto!("infernum");
+}
+
+use proto::{
+    InferRequest, InferResponse, InferToken,
+    infernum_service_server::{InfernumService, InfernumServiceServer},
+};
+
+/// Implements the generated `InfernumService` trait over a shared engine.
+pub struct InfernumGrpcService {
+    engine: Arc<InfernumEngine<PaligemmaModel>>,
+}
+
+impl InfernumGrpcService {
+    /// Wraps `engine` into a server ready to be added to a `tonic::transport::Server`.
+    pub fn new(engine: Arc<InfernumEngine<PaligemmaModel>>) -> InfernumServiceServer<Self> {
+        InfernumServiceServer::new(Self { engine })
+    }
+}
+
+fn image_from_request(request: &InferRequest) -> Result<Image<u8, 3, CpuAllocator>, Status> {
+    if request.channels != 3 {
+        return Err(Status::invalid_argument(format!(
+            "expected 3 channels, got {}",
+            request.channels
+        )));
+    }
+
+    Image::new(
+        ImageSize {
+            width: request.width as usize,
+            height: request.height as usize,
+        },
+        request.image_data.clone(),
+        CpuAllocator,
+    )
+    .map_err(|e| Status::invalid_argument(format!("invalid image data: {e}")))
+}
+
+fn schedule_error_to_status(error: ScheduleError) -> Status {
+    match error {
+        ScheduleError::QueueFull => Status::resource_exhausted(error.to_string()),
+        ScheduleError::EngineStopped => Status::unavailable(error.to_string()),
+    }
+}
+
+#[tonic::async_trait]
+impl InfernumService for InfernumGrpcService {
+    async fn infer(
+        &self,
+        request: Request<InferRequest>,
+    ) -> Result<Response<InferResponse>, Status> {
+        let request = request.into_inner();
+        let image = image_from_request(&request)?;
+
+        let job_id = self
+            .engine
+            .schedule_inference(PaligemmaRequest {
+                image,
+                prompt: request.prompt,
+                sample_len: request.sample_len as usize,
+            })
+            .map_err(schedule_error_to_status)?;
+
+        loop {
+            match self.engine.poll_by_id(job_id) {
+                InfernumEngineResult::Success(result) => {
+                    return Ok(Response::new(InferResponse {
+                        response: result.response.result,
+                        duration_nanos: result.duration.as_nanos() as u64,
+                    }));
+                }
+                InfernumEngineResult::Error(message) => return Err(Status::internal(message)),
+                InfernumEngineResult::NotFound => {
+                    return Err(Status::internal("job disappeared from the registry"));
+                }
+                InfernumEngineResult::Pending(_) => {
+                    tokio::time::sleep(Duration::from_millis(5)).await;
+                }
+            }
+        }
+    }
+
+    type InferStreamStream = Pin<Box<dyn Stream<Item = Result<InferToken, Status>> + Send>>;
+
+    async fn infer_stream(
+        &self,
+        request: Request<InferRequest>,
+    ) -> Result<Response<Self::InferStreamStream>, Status> {
+        let request = request.into_inner();
+        let image = image_from_request(&request)?;
+
+        let (job_id, token_rx) = self
+            .engine
+            .schedule_inference_stream(PaligemmaRequest {
+                image,
+                prompt: request.prompt,
+                sample_len: request.sample_len as usize,
+            })
+            .map_err(schedule_error_to_status)?;
+
+        // Forward tokens from the engine's blocking channel to an async one
+        // the gRPC stream can poll, mirroring the SSE bridge used by
+        // `POST /inference/stream`.
+        let engine = self.engine.clone();
+        let (event_tx, event_rx) = tokio::sync::mpsc::unbounded_channel();
+        std::thread::spawn(move || {
+            while let Ok(token) = token_rx.recv() {
+                let message = InferToken {
+                    token,
+                    duration_nanos: 0,
+                };
+                if event_tx.send(Ok(message)).is_err() {
+                    return;
+                }
+            }
+
+            let duration_nanos = loop {
+                match engine.poll_by_id(job_id) {
+                    InfernumEngineResult::Success(result) => {
+                        break result.duration.as_nanos() as u64;
+                    }
+                    InfernumEngineResult::Error(message) => {
+                        let _ = event_tx.send(Err(Status::internal(message)));
+                        return;
+                    }
+                    InfernumEngineResult::NotFound => return,
+                    InfernumEngineResult::Pending(_) => {
+                        std::thread::sleep(Duration::from_millis(5));
+                    }
+                }
+            };
+
+            let _ = event_tx.send(Ok(InferToken {
+                token: String::new(),
+                duration_nanos,
+            }));
+        });
+
+        Ok(Response::new(Box::pin(UnboundedReceiverStream::new(
+            event_rx,
+        ))))
+    }
+}
@@ -1,24 +1,37 @@
 use argh::FromArgs;
 use axum::{
-    Json, Router,
-    extract::State,
-    response::IntoResponse,
+    Extension, Json, Router,
+    extract::{Path, State},
+    response::{
+        IntoResponse, Response,
+        sse::{Event, Sse},
+    },
     routing::{get, post},
 };
 use kornia_image::{Image, ImageSize, allocator::CpuAllocator};
 use kornia_infernum::{
-    InfernumEngine, InfernumEngineResult, InfernumEngineState, InfernumModel, RequestMetadata,
+    BatchConfig, DEFAULT_QUEUE_CAPACITY, InfernumAccessLogLayer, InfernumEngine,
+    InfernumEngineResult, InfernumModel, JobId, RequestId, RequestMetadata, ScheduleError,
 };
 use kornia_vlm::paligemma::{Paligemma, PaligemmaConfig, PaligemmaError};
 use reqwest::StatusCode;
 use serde_json::json;
-use std::{path::PathBuf, sync::Arc};
+use std::{convert::Infallible, net::SocketAddr, path::PathBuf, sync::Arc, time::Duration};
+use tokio_stream::{StreamExt, wrappers::UnboundedReceiverStream};
 
+mod grpc;
 mod messages;
 
 // defaults for the server
 const DEFAULT_HOST: &str = "0.0.0.0";
 const DEFAULT_PORT: u16 = 3000;
+const DEFAULT_GRPC_PORT: u16 = 3001;
+const DEFAULT_WORKERS: usize = 1;
+const DEFAULT_MAX_BATCH_SIZE: usize = 1;
+const DEFAULT_MAX_WAIT_MS: u64 = 0;
+
+/// Seconds suggested to clients via `Retry-After` when the queue is full.
+const QUEUE_FULL_RETRY_AFTER_SECS: u64 = 1;
 
 #[derive(FromArgs)]
 /// Infernum is a tool for running inference on images.
@@ -30,47 +43,161 @@ struct InfernumArgs {
     /// the port to run the server on
     #[argh(option, short = 'p', default = "DEFAULT_PORT")]
     port: u16,
+
+    /// the port to run the gRPC server on
+    #[argh(option, short = 'g', default = "DEFAULT_GRPC_PORT")]
+    grpc_port: u16,
+
+    /// the number of model replicas to run concurrently
+    #[argh(option, short = 'w', default = "DEFAULT_WORKERS")]
+    workers: usize,
+
+    /// the maximum number of requests buffered before a worker picks them up
+    #[argh(option, short = 'q', default = "DEFAULT_QUEUE_CAPACITY")]
+    queue_capacity: usize,
+
+    /// the maximum number of requests a worker coalesces into one `run_batch`
+    /// call
+    #[argh(option, short = 'b', default = "DEFAULT_MAX_BATCH_SIZE")]
+    max_batch_size: usize,
+
+    /// milliseconds a worker waits for a batch to fill before running it
+    #[argh(option, short = 'm', default = "DEFAULT_MAX_WAIT_MS")]
+    max_wait_ms: u64,
+
+    /// log each request's method, path, peer address, and latency
+    #[argh(switch, short = 'l')]
+    log_requests: bool,
+}
+
+/// Maps a failed scheduling attempt to an HTTP response, so callers get
+/// honest backpressure instead of the request being accepted then lost.
+fn schedule_error_response(error: ScheduleError) -> Response {
+    let body = Json(json!({ "error": error.to_string() }));
+    match error {
+        ScheduleError::QueueFull => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            [("retry-after", QUEUE_FULL_RETRY_AFTER_SECS.to_string())],
+            body,
+        )
+            .into_response(),
+        ScheduleError::EngineStopped => (StatusCode::SERVICE_UNAVAILABLE, body).into_response(),
+    }
 }
 
 async fn post_inference(
     State(engine): State<Arc<InfernumEngine<PaligemmaModel>>>,
+    Extension(RequestId(job_id)): Extension<RequestId>,
     Json(payload): Json<messages::InferenceRequest>,
-) -> impl IntoResponse {
-    if engine.state() != InfernumEngineState::Idle {
-        log::debug!("Engine is still processing");
-        return (
-            StatusCode::BAD_REQUEST,
-            Json(json!({ "error": "Engine is still processing" })),
-        );
+) -> Response {
+    // Read image based on extension
+    let img = match read_image_from_path(&payload.image_path) {
+        Ok(img) => img,
+        Err(error_msg) => {
+            return (StatusCode::BAD_REQUEST, Json(json!({ "error": error_msg }))).into_response();
+        }
+    };
+
+    // schedule the inference under the HTTP request id, so the two correlate
+    if let Err(error) = engine.schedule_inference_with_id(
+        job_id,
+        PaligemmaRequest {
+            image: img,
+            prompt: payload.prompt.clone(),
+            sample_len: 50,
+        },
+    ) {
+        log::warn!("Failed to schedule job {job_id}: {error}");
+        return schedule_error_response(error);
     }
 
+    log::info!("Scheduled inference as job {job_id}");
+
+    (
+        StatusCode::OK,
+        Json(json!(messages::ScheduleResponse { job_id })),
+    )
+        .into_response()
+}
+
+async fn post_inference_stream(
+    State(engine): State<Arc<InfernumEngine<PaligemmaModel>>>,
+    Extension(RequestId(job_id)): Extension<RequestId>,
+    Json(payload): Json<messages::InferenceRequest>,
+) -> Result<impl IntoResponse, Response> {
     // Read image based on extension
     let img = match read_image_from_path(&payload.image_path) {
         Ok(img) => img,
         Err(error_msg) => {
-            return (StatusCode::BAD_REQUEST, Json(json!({ "error": error_msg })));
+            return Err(
+                (StatusCode::BAD_REQUEST, Json(json!({ "error": error_msg }))).into_response(),
+            );
         }
     };
 
-    // schedule the inference
-    engine.schedule_inference(PaligemmaRequest {
-        image: img,
-        prompt: payload.prompt.clone(),
-        sample_len: 50,
-    });
+    let token_rx = engine
+        .schedule_inference_stream_with_id(
+            job_id,
+            PaligemmaRequest {
+                image: img,
+                prompt: payload.prompt.clone(),
+                sample_len: 50,
+            },
+        )
+        .map_err(|error| {
+            log::warn!("Failed to schedule streaming job {job_id}: {error}");
+            schedule_error_response(error)
+        })?;
 
-    log::info!("Scheduled inference successfully");
+    log::info!("Scheduled streaming inference as job {job_id}");
 
-    (StatusCode::OK, Json(json!({ "status": "scheduled" })))
+    // Forward tokens from the worker's blocking channel to an async one the
+    // SSE stream can poll, then emit a final `done` event with telemetry.
+    let (event_tx, event_rx) = tokio::sync::mpsc::unbounded_channel::<Event>();
+    std::thread::spawn(move || {
+        while let Ok(token) = token_rx.recv() {
+            if event_tx.send(Event::default().data(token)).is_err() {
+                return;
+            }
+        }
+
+        let final_event = loop {
+            match engine.poll_by_id(job_id) {
+                InfernumEngineResult::Success(engine_result) => {
+                    break Event::default().event("done").json_data(json!({
+                        "duration": engine_result.duration,
+                        "start_time": engine_result.start_time.elapsed().as_nanos(),
+                    }))
+                }
+                InfernumEngineResult::Error(e) => {
+                    break Event::default()
+                        .event("error")
+                        .json_data(json!({ "message": e }))
+                }
+                InfernumEngineResult::NotFound => return,
+                InfernumEngineResult::Pending(_) => {
+                    std::thread::sleep(Duration::from_millis(5));
+                }
+            }
+        };
+
+        if let Ok(event) = final_event {
+            let _ = event_tx.send(event);
+        }
+    });
+
+    Ok(Sse::new(
+        UnboundedReceiverStream::new(event_rx).map(Ok::<_, Infallible>),
+    ))
 }
 
 async fn get_result(
     State(engine): State<Arc<InfernumEngine<PaligemmaModel>>>,
+    Path(job_id): Path<JobId>,
 ) -> impl IntoResponse {
-    // If we're here, there should be a result available
-    match engine.try_poll_response() {
+    match engine.poll_by_id(job_id) {
         InfernumEngineResult::Success(engine_result) => {
-            log::info!("Result received successfully");
+            log::info!("Result for job {job_id} received successfully");
             let inference_response = messages::InferenceResponse {
                 prompt: engine_result.request_metadata.prompt,
                 start_time: engine_result.start_time.elapsed().as_nanos(),
@@ -86,18 +213,16 @@ async fn get_result(
                 })),
             )
         }
-        InfernumEngineResult::Empty(state) => {
-            log::warn!("Expected a result but none was available");
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(
-                    json!({ "status": state.as_str(), "message": "Expected result not available" }),
-                ),
-            )
-        }
+        InfernumEngineResult::Pending(status) => (
+            StatusCode::OK,
+            Json(json!({ "status": status.as_str() })),
+        ),
+        InfernumEngineResult::NotFound => (
+            StatusCode::NOT_FOUND,
+            Json(json!({ "status": "not_found", "message": "No such job" })),
+        ),
         InfernumEngineResult::Error(e) => {
-            // This is an unexpected state - we should have a result
-            log::warn!("Expected a result but none was available");
+            log::warn!("Job {job_id} failed: {e}");
             (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(json!({ "status": "error", "message": e })),
@@ -106,6 +231,31 @@ async fn get_result(
     }
 }
 
+async fn get_status(State(engine): State<Arc<InfernumEngine<PaligemmaModel>>>) -> impl IntoResponse {
+    let state = engine.state();
+    (
+        StatusCode::OK,
+        Json(json!({
+            "busy": state.busy,
+            "total": state.total,
+            "status": state.as_str(),
+        })),
+    )
+}
+
+async fn get_jobs(State(engine): State<Arc<InfernumEngine<PaligemmaModel>>>) -> impl IntoResponse {
+    let jobs: Vec<messages::JobSummary> = engine
+        .list_jobs()
+        .into_iter()
+        .map(|(job_id, status)| messages::JobSummary {
+            job_id,
+            status: status.as_str().to_string(),
+        })
+        .collect();
+
+    (StatusCode::OK, Json(json!({ "jobs": jobs })))
+}
+
 // Helper function
 fn read_image_from_path(
     path: &PathBuf,
@@ -151,6 +301,12 @@ struct PaligemmaResponse {
     result: String,
 }
 
+impl std::fmt::Display for PaligemmaResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.result)
+    }
+}
+
 impl InfernumModel for PaligemmaModel {
     type Request = PaligemmaRequest;
     type Response = PaligemmaResponse;
@@ -163,31 +319,76 @@ impl InfernumModel for PaligemmaModel {
 
         Ok(PaligemmaResponse { result })
     }
+
+    // `run_stream` is intentionally not overridden: `Paligemma::inference` has
+    // no token-by-token callback to hook a sink into, so this falls back to
+    // the trait default (run to completion, then emit the full string as one
+    // token). `POST /inference/stream` is therefore not yet incremental for
+    // this model — it buffers exactly like `POST /inference` and only gains
+    // real partial output once kornia_vlm exposes a streaming generation API.
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     env_logger::init();
+    // `InfernumAccessLog` opens a `tracing` span per request; without a
+    // registered `Subscriber` that span is never recorded by anything, so
+    // install one here (`log`'s global logger above is a separate registry
+    // and doesn't cover it).
+    tracing_subscriber::fmt::init();
     let args: InfernumArgs = argh::from_env();
 
     // format the host and port
     let addr = format!("{}:{}", args.host, args.port);
 
-    let model = Paligemma::new(PaligemmaConfig::default())?;
-    let engine = Arc::new(InfernumEngine::new(PaligemmaModel(model)));
+    let engine = Arc::new(InfernumEngine::with_batching(
+        || Paligemma::new(PaligemmaConfig::default()).map(PaligemmaModel),
+        args.workers,
+        args.queue_capacity,
+        BatchConfig {
+            max_batch_size: args.max_batch_size,
+            max_wait: Duration::from_millis(args.max_wait_ms),
+        },
+    )?);
+
+    let grpc_engine = engine.clone();
 
     let app = Router::new()
         .route("/", get(|| async { "Welcome to Infernum!" }))
         .route("/inference", post(post_inference))
-        .route("/results", get(get_result))
+        .route("/inference/stream", post(post_inference_stream))
+        .route("/results/{id}", get(get_result))
+        .route("/status", get(get_status))
+        .route("/jobs", get(get_jobs))
+        .layer(InfernumAccessLogLayer::new(args.log_requests))
         .with_state(engine);
 
+    let grpc_addr: SocketAddr = format!("{}:{}", args.host, args.grpc_port).parse()?;
+
     log::info!("ðŸš€ Starting the server");
-    log::info!("ðŸ”¥ Listening on: {}", addr);
+    log::info!("ðŸ”¥ HTTP listening on: {addr}");
+    log::info!("ðŸ”¥ gRPC listening on: {grpc_addr}");
     log::info!("ðŸ”§ Press Ctrl+C to stop the server");
 
-    let listener = tokio::net::TcpListener::bind(addr).await?;
-    axum::serve(listener, app).await?;
+    let http_server = async {
+        let listener = tokio::net::TcpListener::bind(&addr).await?;
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await?;
+        Ok::<(), Box<dyn std::error::Error>>(())
+    };
+
+    let grpc_server = async {
+        tonic::transport::Server::builder()
+            .add_service(grpc::InfernumGrpcService::new(grpc_engine))
+            .serve(grpc_addr)
+            .await?;
+        Ok::<(), Box<dyn std::error::Error>>(())
+    };
+
+    tokio::try_join!(http_server, grpc_server)?;
 
     Ok(())
 }
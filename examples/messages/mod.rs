@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::{path::PathBuf, time::Duration};
+use uuid::Uuid;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct InferenceRequest {
@@ -7,6 +8,13 @@ pub struct InferenceRequest {
     pub image_path: PathBuf,
 }
 
+/// Returned immediately after scheduling, so the caller can later fetch the
+/// result with `GET /results/{job_id}`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ScheduleResponse {
+    pub job_id: Uuid,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct InferenceResponse {
     pub prompt: String,
@@ -14,3 +22,10 @@ pub struct InferenceResponse {
     pub duration: Duration,
     pub response: String,
 }
+
+/// Summary of a single job as listed by `GET /jobs`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct JobSummary {
+    pub job_id: Uuid,
+    pub status: String,
+}
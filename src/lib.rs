@@ -1,14 +1,30 @@
 use std::{
-    sync::{Arc, Mutex, mpsc},
+    collections::HashMap,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicBool, Ordering},
+        mpsc,
+    },
     thread::JoinHandle,
     time::{Duration, Instant},
 };
+use uuid::Uuid;
+
+mod middleware;
+pub use middleware::{InfernumAccessLog, InfernumAccessLogLayer, RequestId};
+
+/// Unique identifier assigned to a scheduled job.
+///
+/// Generated at schedule time so ids are globally unique and safe to expose
+/// in URLs, unlike a wrapping counter.
+pub type JobId = Uuid;
 
 // Type alias to simplify complex types
-type EngineReceiver<M> = Arc<
+type JobMap<M> = Arc<
     Mutex<
-        mpsc::Receiver<
-            InfernumEngineResponse<
+        HashMap<
+            JobId,
+            JobEntry<
                 <<M as InfernumModel>::Request as RequestMetadata>::Metadata,
                 <M as InfernumModel>::Response,
             >,
@@ -30,23 +46,199 @@ pub trait InfernumModel {
 
     /// Runs inference on the given request and returns a response or error.
     fn run(&mut self, request: Self::Request) -> Result<Self::Response, Self::Error>;
+
+    /// Runs inference over a batch of requests in a single call.
+    ///
+    /// Models that can exploit batched execution (e.g. a GPU forward pass
+    /// over several prompts at once) should override this; the default
+    /// implementation simply loops over `run`, so batching is always safe to
+    /// enable even for models that don't implement it.
+    ///
+    /// Implementations must return exactly one result per request, in the
+    /// same order, so the caller can scatter results back to the requests
+    /// that produced them.
+    fn run_batch(
+        &mut self,
+        requests: Vec<Self::Request>,
+    ) -> Vec<Result<Self::Response, Self::Error>> {
+        requests
+            .into_iter()
+            .map(|request| self.run(request))
+            .collect()
+    }
+
+    /// Runs inference, invoking `sink` once per token as it is produced.
+    ///
+    /// Models that can decode incrementally (e.g. autoregressive generation)
+    /// should override this to call `sink` as each token is ready. The
+    /// default implementation has no incremental output to offer, so it
+    /// simply runs to completion and emits the whole response once.
+    fn run_stream(
+        &mut self,
+        request: Self::Request,
+        sink: &mut dyn FnMut(String),
+    ) -> Result<Self::Response, Self::Error>
+    where
+        Self::Response: ToString,
+    {
+        let response = self.run(request)?;
+        sink(response.to_string());
+        Ok(response)
+    }
 }
 
-/// Represents the current state of the inference engine.
-#[derive(Clone, Debug, PartialEq)]
-pub enum InfernumEngineState {
-    /// The engine is idle and ready to accept new inference requests.
-    Idle,
-    /// The engine is currently processing an inference request.
-    Processing,
+/// Builds one model instance per worker in a pool.
+///
+/// `M` is typically not `Clone` (it may hold a loaded checkpoint or device
+/// handle), so a worker pool needs a way to construct several independent
+/// instances instead. Implemented automatically for any
+/// `Fn() -> Result<M, M::Error>` closure.
+pub trait ModelFactory<M: InfernumModel>: Send + Sync + 'static {
+    /// Constructs a new model instance for a worker.
+    fn build(&self) -> Result<M, M::Error>;
+}
+
+impl<M, F> ModelFactory<M> for F
+where
+    M: InfernumModel,
+    F: Fn() -> Result<M, M::Error> + Send + Sync + 'static,
+{
+    fn build(&self) -> Result<M, M::Error> {
+        self()
+    }
+}
+
+/// Represents the current occupancy of the inference engine's worker pool.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InfernumEngineState {
+    /// Number of workers currently running an inference.
+    pub busy: usize,
+    /// Total number of workers in the pool.
+    pub total: usize,
 }
 
 impl InfernumEngineState {
-    /// Returns the state as a string representation.
+    /// Returns a human-readable occupancy string, e.g. `"3/8 workers busy"`.
+    pub fn as_str(&self) -> String {
+        format!("{}/{} workers busy", self.busy, self.total)
+    }
+}
+
+/// Observable lifecycle state of a single scheduled job.
+///
+/// Mirrors the agent/job-state model: a job starts `Queued`, moves to
+/// `Running` once a worker picks it up, and finally resolves to `Completed`
+/// or `Failed`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JobStatus {
+    /// The job is waiting to be picked up by the background thread.
+    Queued,
+    /// The job is currently being processed.
+    Running,
+    /// The job finished successfully.
+    Completed,
+    /// The job finished with an error.
+    Failed,
+}
+
+impl JobStatus {
+    /// Returns the status as a string representation.
     pub fn as_str(&self) -> &'static str {
         match self {
-            InfernumEngineState::Idle => "idle",
-            InfernumEngineState::Processing => "processing",
+            JobStatus::Queued => "queued",
+            JobStatus::Running => "running",
+            JobStatus::Completed => "completed",
+            JobStatus::Failed => "failed",
+        }
+    }
+}
+
+/// Internal registry entry backing a `JobId`, carrying the data relevant to
+/// its current `JobStatus`.
+enum JobEntry<Metadata, Response> {
+    Queued,
+    Running,
+    Completed(InfernumEngineResponse<Metadata, Response>),
+    Failed { error: String, finished_at: Instant },
+}
+
+impl<Metadata, Response> JobEntry<Metadata, Response> {
+    fn status(&self) -> JobStatus {
+        match self {
+            JobEntry::Queued => JobStatus::Queued,
+            JobEntry::Running => JobStatus::Running,
+            JobEntry::Completed(_) => JobStatus::Completed,
+            JobEntry::Failed { .. } => JobStatus::Failed,
+        }
+    }
+
+    /// When a terminal (`Completed`/`Failed`) entry finished, for the
+    /// reaper's age-based eviction. `None` for `Queued`/`Running`, which the
+    /// reaper never touches.
+    fn finished_at(&self) -> Option<Instant> {
+        match self {
+            JobEntry::Completed(response) => Some(response.start_time + response.duration),
+            JobEntry::Failed { finished_at, .. } => Some(*finished_at),
+            JobEntry::Queued | JobEntry::Running => None,
+        }
+    }
+}
+
+/// Default capacity of the request queue when one isn't specified explicitly.
+pub const DEFAULT_QUEUE_CAPACITY: usize = 16;
+
+/// How long a terminal (`Completed`/`Failed`) job entry is kept if nobody
+/// ever polls it, before the reaper evicts it.
+///
+/// Without this, a job whose caller never calls `poll_by_id` (e.g. an HTTP
+/// client that schedules a job and never fetches its result) would stay in
+/// the registry forever, leaking memory over a long-running server's uptime.
+pub const DEFAULT_JOB_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// How often the reaper sweeps the registry for expired terminal entries.
+/// Also bounds how long `stop()` waits on the reaper thread to notice the
+/// engine has been stopped.
+const REAPER_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Error returned when a request could not be scheduled.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScheduleError {
+    /// The request queue is at capacity; retry later.
+    QueueFull,
+    /// The engine has been stopped and is no longer accepting requests.
+    EngineStopped,
+}
+
+impl std::fmt::Display for ScheduleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScheduleError::QueueFull => write!(f, "request queue is full"),
+            ScheduleError::EngineStopped => write!(f, "engine has been stopped"),
+        }
+    }
+}
+
+impl std::error::Error for ScheduleError {}
+
+/// Configures how a worker coalesces queued requests into `run_batch` calls.
+///
+/// Defaults to `max_batch_size: 1`, which disables batching: every request
+/// is run on its own, identical to the non-batching worker behavior.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BatchConfig {
+    /// Maximum number of requests coalesced into a single `run_batch` call.
+    pub max_batch_size: usize,
+    /// How long a worker waits for additional requests to arrive after the
+    /// first, before running the batch it already has. Ignored once
+    /// `max_batch_size` requests have been collected.
+    pub max_wait: Duration,
+}
+
+impl Default for BatchConfig {
+    fn default() -> Self {
+        Self {
+            max_batch_size: 1,
+            max_wait: Duration::ZERO,
         }
     }
 }
@@ -67,15 +259,18 @@ pub trait RequestMetadata {
 /// Internal request wrapper used by the engine to track inference requests.
 pub struct InfernumEngineRequest<Req> {
     /// Unique identifier for this inference request.
-    pub id: u8,
+    pub id: JobId,
     /// The actual request data to be processed by the model.
     pub request: Req,
+    /// When set, the worker streams each decoded token over this channel
+    /// instead of only recording the final response.
+    pub stream_tx: Option<mpsc::Sender<String>>,
 }
 
 /// Response returned by the engine containing both the model's response and telemetry data.
 pub struct InfernumEngineResponse<Metadata, Res> {
     /// Unique identifier matching the original request.
-    pub id: u8,
+    pub id: JobId,
     /// Timestamp when the inference started.
     pub start_time: Instant,
     /// Total time taken for the inference.
@@ -86,15 +281,17 @@ pub struct InfernumEngineResponse<Metadata, Res> {
     pub response: Res,
 }
 
-/// Result type returned when polling for inference results.
+/// Result type returned when polling for a job's result by id.
 pub enum InfernumEngineResult<M: InfernumModel + Send + 'static>
 where
     M::Request: RequestMetadata,
 {
     /// Successful inference with the response data.
     Success(InfernumEngineResponse<<M::Request as RequestMetadata>::Metadata, M::Response>),
-    /// No result available yet, with current engine state.
-    Empty(InfernumEngineState),
+    /// The job is known but hasn't produced a result yet.
+    Pending(JobStatus),
+    /// No job exists for the given id.
+    NotFound,
     /// An error occurred during inference or engine operation.
     Error(String),
 }
@@ -109,121 +306,443 @@ pub struct InfernumEngine<M: InfernumModel + Send + 'static>
 where
     M::Error: Send + 'static,
     M::Request: Send + RequestMetadata + 'static,
-    M::Response: Send + 'static,
+    M::Response: Send + ToString + 'static,
 {
     state: Arc<Mutex<InfernumEngineState>>,
-    req_tx: Option<mpsc::Sender<InfernumEngineRequest<M::Request>>>,
-    rep_rx: EngineReceiver<M>,
-    inference_handle: Option<JoinHandle<Result<(), M::Error>>>,
-    id_counter: Arc<Mutex<u8>>,
+    req_tx: Option<mpsc::SyncSender<InfernumEngineRequest<M::Request>>>,
+    jobs: JobMap<M>,
+    worker_handles: Vec<JoinHandle<()>>,
+    reaper_running: Arc<AtomicBool>,
 }
 
 impl<M: InfernumModel + Send + 'static> InfernumEngine<M>
 where
     M::Error: Send + 'static,
     M::Request: Send + RequestMetadata + 'static,
-    M::Response: Send + 'static,
+    M::Response: Send + ToString + 'static,
 {
-    /// Creates a new inference engine with the given model.
+    /// Creates a new inference engine with a single worker running the given model.
     ///
     /// The engine will spawn a background thread to handle inference requests
-    /// asynchronously. The model will be moved to this background thread.
+    /// asynchronously. The model will be moved to this background thread. For
+    /// a pool of several concurrent workers, use [`InfernumEngine::with_workers`].
     ///
     /// # Arguments
     /// * `model` - The model implementation that will handle inference requests
     ///
     /// # Returns
     /// A new `InfernumEngine` instance ready to accept inference requests
-    pub fn new(mut model: M) -> Self {
-        let (req_tx, req_rx) = mpsc::channel::<InfernumEngineRequest<M::Request>>();
-        let (rep_tx, rep_rx) = mpsc::channel::<
-            InfernumEngineResponse<<M::Request as RequestMetadata>::Metadata, M::Response>,
-        >();
-        let state = Arc::new(Mutex::new(InfernumEngineState::Idle));
-
-        let inference_handle = std::thread::spawn({
+    pub fn new(model: M) -> Self {
+        let model = Mutex::new(Some(model));
+        Self::with_workers(
+            move || {
+                Ok(model
+                    .lock()
+                    .unwrap()
+                    .take()
+                    .expect("InfernumEngine::new factory invoked more than once"))
+            },
+            1,
+            DEFAULT_QUEUE_CAPACITY,
+        )
+        .unwrap_or_else(|_: M::Error| unreachable!("single-model factory never fails"))
+    }
+
+    /// Creates a new inference engine backed by a pool of `n` workers.
+    ///
+    /// Since `M` is typically not `Clone`, `factory` is called once per
+    /// worker to construct its own model instance, which that worker then
+    /// owns on its own thread for the lifetime of the engine. Queued
+    /// requests are pulled from a shared work queue by whichever worker is
+    /// free next, so throughput scales with the number of workers.
+    ///
+    /// The request queue is bounded to `queue_capacity` entries; once full,
+    /// `schedule_inference` and friends return `ScheduleError::QueueFull`
+    /// instead of buffering unboundedly or silently dropping work.
+    ///
+    /// # Arguments
+    /// * `factory` - Builds one model instance per worker
+    /// * `n` - Number of workers to spawn; must be at least 1
+    /// * `queue_capacity` - Maximum number of requests buffered before a
+    ///   worker picks them up
+    ///
+    /// # Returns
+    /// A new `InfernumEngine` instance, or the first error returned while
+    /// constructing a worker's model.
+    pub fn with_workers(
+        factory: impl ModelFactory<M>,
+        n: usize,
+        queue_capacity: usize,
+    ) -> Result<Self, M::Error> {
+        Self::with_batching(factory, n, queue_capacity, BatchConfig::default())
+    }
+
+    /// Creates a new inference engine backed by a pool of `n` workers that
+    /// coalesce queued requests into batched `run_batch` calls.
+    ///
+    /// See [`InfernumEngine::with_workers`] for the worker-pool semantics;
+    /// `batch_config` additionally controls how many queued requests a
+    /// worker gathers before issuing a single `run_batch` call. Streaming
+    /// requests (from `schedule_inference_stream`) are never batched, since
+    /// `run_batch` has no per-request sink to stream tokens through.
+    ///
+    /// # Arguments
+    /// * `factory` - Builds one model instance per worker
+    /// * `n` - Number of workers to spawn; must be at least 1
+    /// * `queue_capacity` - Maximum number of requests buffered before a
+    ///   worker picks them up
+    /// * `batch_config` - Limits on how many requests a worker batches
+    ///   together, and how long it waits to fill a batch
+    ///
+    /// # Returns
+    /// A new `InfernumEngine` instance, or the first error returned while
+    /// constructing a worker's model.
+    pub fn with_batching(
+        factory: impl ModelFactory<M>,
+        n: usize,
+        queue_capacity: usize,
+        batch_config: BatchConfig,
+    ) -> Result<Self, M::Error> {
+        assert!(n > 0, "InfernumEngine requires at least one worker");
+        assert!(
+            batch_config.max_batch_size > 0,
+            "BatchConfig::max_batch_size must be at least 1"
+        );
+
+        let (req_tx, req_rx) =
+            mpsc::sync_channel::<InfernumEngineRequest<M::Request>>(queue_capacity);
+        let req_rx = Arc::new(Mutex::new(req_rx));
+        let state = Arc::new(Mutex::new(InfernumEngineState { busy: 0, total: n }));
+        let jobs: JobMap<M> = Arc::new(Mutex::new(HashMap::new()));
+
+        let mut worker_handles = Vec::with_capacity(n);
+        for worker_id in 0..n {
+            let mut model = factory.build()?;
+            let req_rx = req_rx.clone();
             let state = state.clone();
-            move || -> Result<(), M::Error> {
-                while let Ok(req) = req_rx.recv() {
-                    log::debug!("Scheduling a new inference");
+            let jobs = jobs.clone();
+
+            worker_handles.push(std::thread::spawn(move || {
+                // A streaming request pulled off the queue while draining a
+                // batch is stashed here instead of being folded into it, since
+                // `run_batch` has no per-request sink to stream through.
+                let mut pending: Option<InfernumEngineRequest<M::Request>> = None;
+
+                loop {
+                    let req = match pending.take() {
+                        Some(req) => req,
+                        None => {
+                            let rx = req_rx.lock().unwrap();
+                            match rx.recv() {
+                                Ok(req) => req,
+                                Err(_) => break,
+                            }
+                        }
+                    };
+
+                    if req.stream_tx.is_some() {
+                        log::debug!("Worker {worker_id} picked up streaming job {}", req.id);
 
-                    // Extract lightweight metadata before consuming the request
-                    let request_metadata = req.request.metadata();
+                        let request_metadata = req.request.metadata();
+                        jobs.lock().unwrap().insert(req.id, JobEntry::Running);
+                        state.lock().unwrap().busy += 1;
+                        let start_time = Instant::now();
 
-                    *state.lock().unwrap() = InfernumEngineState::Processing;
+                        let stream_tx = req.stream_tx.as_ref().expect("checked above");
+                        let mut sink = |token: String| {
+                            let _ = stream_tx.send(token);
+                        };
+                        let result = model.run_stream(req.request, &mut sink);
+
+                        let entry = match result {
+                            Ok(response) => JobEntry::Completed(InfernumEngineResponse {
+                                id: req.id,
+                                start_time,
+                                duration: start_time.elapsed(),
+                                request_metadata,
+                                response,
+                            }),
+                            Err(error) => {
+                                log::error!("Inference failed: {error}");
+                                JobEntry::Failed {
+                                    error: error.to_string(),
+                                    finished_at: Instant::now(),
+                                }
+                            }
+                        };
+                        jobs.lock().unwrap().insert(req.id, entry);
+
+                        log::debug!("Inference completed");
+                        state.lock().unwrap().busy -= 1;
+                        continue;
+                    }
+
+                    // Drain additional non-streaming requests to fill the
+                    // batch, up to `max_batch_size` or until `max_wait`
+                    // elapses since the first request arrived, whichever
+                    // comes first. Under light load no more requests show up
+                    // and the batch falls back to a single request, so
+                    // latency isn't penalized.
+                    let mut batch = vec![req];
+                    let deadline = Instant::now() + batch_config.max_wait;
+                    while batch.len() < batch_config.max_batch_size {
+                        // Always try once before checking the deadline, so
+                        // requests already sitting in the channel are still
+                        // coalesced even when `max_wait` is zero.
+                        let next = {
+                            let rx = req_rx.lock().unwrap();
+                            rx.try_recv().ok()
+                        };
+                        match next {
+                            Some(next_req) if next_req.stream_tx.is_some() => {
+                                pending = Some(next_req);
+                                break;
+                            }
+                            Some(next_req) => batch.push(next_req),
+                            None if Instant::now() < deadline => {
+                                std::thread::sleep(Duration::from_millis(1));
+                            }
+                            None => break,
+                        }
+                    }
+
+                    log::debug!(
+                        "Worker {worker_id} picked up batch of {} job(s)",
+                        batch.len()
+                    );
+
+                    let ids: Vec<JobId> = batch.iter().map(|req| req.id).collect();
+                    let metadatas: Vec<_> =
+                        batch.iter().map(|req| req.request.metadata()).collect();
+
+                    {
+                        let mut jobs = jobs.lock().unwrap();
+                        for id in &ids {
+                            jobs.insert(*id, JobEntry::Running);
+                        }
+                    }
+                    state.lock().unwrap().busy += 1;
                     let start_time = Instant::now();
 
-                    let response = model.run(req.request)?;
+                    let requests = batch.into_iter().map(|req| req.request).collect();
+                    let results = model.run_batch(requests);
+                    let duration = start_time.elapsed();
 
-                    log::debug!("Inference completed");
+                    let mut jobs_guard = jobs.lock().unwrap();
+                    for ((id, request_metadata), result) in
+                        ids.into_iter().zip(metadatas).zip(results)
+                    {
+                        let entry = match result {
+                            Ok(response) => JobEntry::Completed(InfernumEngineResponse {
+                                id,
+                                start_time,
+                                duration,
+                                request_metadata,
+                                response,
+                            }),
+                            Err(error) => {
+                                log::error!("Inference failed: {error}");
+                                JobEntry::Failed {
+                                    error: error.to_string(),
+                                    finished_at: Instant::now(),
+                                }
+                            }
+                        };
+                        jobs_guard.insert(id, entry);
+                    }
+                    drop(jobs_guard);
 
-                    let _ = rep_tx.send(InfernumEngineResponse {
-                        id: req.id,
-                        start_time,
-                        duration: start_time.elapsed(),
-                        request_metadata,
-                        response,
-                    });
+                    log::debug!("Batch completed");
+                    state.lock().unwrap().busy -= 1;
+                }
+            }));
+        }
 
-                    *state.lock().unwrap() = InfernumEngineState::Idle;
+        // Reaper: evicts terminal entries nobody ever polled, so a server
+        // handling many jobs over a long uptime doesn't leak the registry.
+        let reaper_running = Arc::new(AtomicBool::new(true));
+        {
+            let jobs = jobs.clone();
+            let running = reaper_running.clone();
+            worker_handles.push(std::thread::spawn(move || {
+                while running.load(Ordering::Relaxed) {
+                    std::thread::sleep(REAPER_INTERVAL);
+                    let now = Instant::now();
+                    jobs.lock()
+                        .unwrap()
+                        .retain(|_, entry| match entry.finished_at() {
+                            Some(finished_at) => now.duration_since(finished_at) < DEFAULT_JOB_TTL,
+                            None => true,
+                        });
                 }
-                Ok(())
-            }
-        });
+            }));
+        }
 
-        Self {
+        Ok(Self {
             state,
             req_tx: Some(req_tx),
-            rep_rx: Arc::new(Mutex::new(rep_rx)),
-            inference_handle: Some(inference_handle),
-            id_counter: Arc::new(Mutex::new(0)),
-        }
+            jobs,
+            worker_handles,
+            reaper_running,
+        })
     }
 
-    /// Returns the current state of the inference engine.
+    /// Returns the current occupancy of the worker pool.
     pub fn state(&self) -> InfernumEngineState {
         self.state.lock().unwrap().clone()
     }
 
-    /// Attempts to retrieve a completed inference result without blocking.
+    /// Attempts to retrieve the result of a specific job without blocking.
+    ///
+    /// Completed and failed jobs are consumed from the registry once
+    /// returned; queued and running jobs are left in place so they can be
+    /// polled again later.
     ///
     /// # Returns
     /// * `Success` - Contains the inference response with telemetry data
-    /// * `Empty` - No result available yet, includes current engine state
-    /// * `Error` - An error occurred during inference or engine operation
-    pub fn try_poll_response(&self) -> InfernumEngineResult<M> {
-        match self.rep_rx.lock().unwrap().try_recv() {
-            Ok(response) => InfernumEngineResult::Success(response),
-            Err(mpsc::TryRecvError::Empty) => InfernumEngineResult::Empty(self.state()),
-            Err(mpsc::TryRecvError::Disconnected) => {
-                log::error!("Response channel disconnected");
-                InfernumEngineResult::Error("Response channel disconnected".to_string())
+    /// * `Pending` - The job is still queued or running
+    /// * `NotFound` - No job exists for the given id
+    /// * `Error` - The job failed during inference
+    pub fn poll_by_id(&self, id: JobId) -> InfernumEngineResult<M> {
+        let mut jobs = self.jobs.lock().unwrap();
+        match jobs.get(&id) {
+            Some(JobEntry::Completed(_)) | Some(JobEntry::Failed { .. }) => {
+                match jobs.remove(&id) {
+                    Some(JobEntry::Completed(response)) => InfernumEngineResult::Success(response),
+                    Some(JobEntry::Failed { error, .. }) => InfernumEngineResult::Error(error),
+                    _ => unreachable!("entry was just matched as Completed or Failed"),
+                }
             }
+            Some(entry) => InfernumEngineResult::Pending(entry.status()),
+            None => InfernumEngineResult::NotFound,
         }
     }
 
+    /// Lists all outstanding jobs with their current status, for observability.
+    pub fn list_jobs(&self) -> Vec<(JobId, JobStatus)> {
+        self.jobs
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(id, entry)| (*id, entry.status()))
+            .collect()
+    }
+
     /// Schedules an inference request for asynchronous processing.
     ///
-    /// The request will be queued and processed by the background thread.
-    /// Each request is assigned a unique ID for tracking purposes.
+    /// The request is queued unconditionally (no more "engine must be idle"
+    /// check) up to the engine's queue capacity, and processed by a worker in
+    /// turn. Each request is assigned a globally unique `JobId` that can
+    /// later be used with `poll_by_id` to retrieve its result.
     ///
     /// # Arguments
     /// * `request` - The inference request to be processed by the model
-    pub fn schedule_inference(&self, request: M::Request) {
-        if let Some(tx) = &self.req_tx {
-            let id = *self.id_counter.lock().unwrap();
-            *self.id_counter.lock().unwrap() += 1;
-            let _ = tx.send(InfernumEngineRequest { id, request });
+    ///
+    /// # Returns
+    /// The `JobId` assigned to this request, or a [`ScheduleError`] if the
+    /// queue is full or the engine has been stopped.
+    pub fn schedule_inference(&self, request: M::Request) -> Result<JobId, ScheduleError> {
+        self.enqueue(Uuid::new_v4(), request, None)
+    }
+
+    /// Schedules an inference request under a caller-chosen `JobId`.
+    ///
+    /// Lets a caller correlate the job with an id it already owns, e.g. the
+    /// id assigned to the inbound HTTP request by [`InfernumAccessLog`], so
+    /// the correlation id matches end-to-end.
+    ///
+    /// # Arguments
+    /// * `id` - The id to assign to this job
+    /// * `request` - The inference request to be processed by the model
+    pub fn schedule_inference_with_id(
+        &self,
+        id: JobId,
+        request: M::Request,
+    ) -> Result<(), ScheduleError> {
+        self.enqueue(id, request, None)?;
+        Ok(())
+    }
+
+    /// Schedules an inference request and streams its tokens as they are produced.
+    ///
+    /// Returns the assigned `JobId` together with a receiver that yields one
+    /// `String` per token emitted by [`InfernumModel::run_stream`]. Once the
+    /// channel is closed, poll the same `JobId` with `poll_by_id` to retrieve
+    /// the final telemetry (duration, start time) for the job.
+    ///
+    /// # Arguments
+    /// * `request` - The inference request to be processed by the model
+    ///
+    /// # Returns
+    /// The assigned `JobId` and a receiver of decoded tokens.
+    pub fn schedule_inference_stream(
+        &self,
+        request: M::Request,
+    ) -> Result<(JobId, mpsc::Receiver<String>), ScheduleError> {
+        let (stream_tx, stream_rx) = mpsc::channel();
+        let id = self.enqueue(Uuid::new_v4(), request, Some(stream_tx))?;
+        Ok((id, stream_rx))
+    }
+
+    /// Schedules a streaming inference request under a caller-chosen `JobId`.
+    ///
+    /// See [`InfernumEngine::schedule_inference_with_id`] and
+    /// [`InfernumEngine::schedule_inference_stream`].
+    pub fn schedule_inference_stream_with_id(
+        &self,
+        id: JobId,
+        request: M::Request,
+    ) -> Result<mpsc::Receiver<String>, ScheduleError> {
+        let (stream_tx, stream_rx) = mpsc::channel();
+        self.enqueue(id, request, Some(stream_tx))?;
+        Ok(stream_rx)
+    }
+
+    fn enqueue(
+        &self,
+        id: JobId,
+        request: M::Request,
+        stream_tx: Option<mpsc::Sender<String>>,
+    ) -> Result<JobId, ScheduleError> {
+        let Some(tx) = &self.req_tx else {
+            return Err(ScheduleError::EngineStopped);
+        };
+
+        // Insert before sending: a worker can dequeue and start (or finish)
+        // the job as soon as `try_send` succeeds, and may already have
+        // written `Running`/`Completed`/`Failed` by the time control returns
+        // here. Inserting `Queued` afterwards would clobber that real status
+        // back to `Queued`, losing the result. Insert first and roll back on
+        // failure instead.
+        self.jobs.lock().unwrap().insert(id, JobEntry::Queued);
+
+        match tx.try_send(InfernumEngineRequest {
+            id,
+            request,
+            stream_tx,
+        }) {
+            Ok(()) => Ok(id),
+            Err(mpsc::TrySendError::Full(_)) => {
+                self.jobs.lock().unwrap().remove(&id);
+                Err(ScheduleError::QueueFull)
+            }
+            Err(mpsc::TrySendError::Disconnected(_)) => {
+                self.jobs.lock().unwrap().remove(&id);
+                Err(ScheduleError::EngineStopped)
+            }
         }
     }
 
-    /// Stops the inference engine and shuts down the background thread.
+    /// Stops the inference engine and shuts down all worker threads.
     ///
-    /// This method will close the request channel and wait for the background
-    /// thread to finish processing any remaining requests.
+    /// This method will close the request channel and wait for every worker
+    /// thread to finish processing any remaining requests. The reaper thread
+    /// is signaled to stop too, though it may take up to `REAPER_INTERVAL` to
+    /// notice and join.
     pub fn stop(&mut self) {
         self.req_tx.take();
-        if let Some(handle) = self.inference_handle.take() {
+        self.reaper_running.store(false, Ordering::Relaxed);
+        for handle in self.worker_handles.drain(..) {
             let _ = handle.join();
         }
     }
@@ -233,9 +752,159 @@ impl<M: InfernumModel + Send + 'static> Drop for InfernumEngine<M>
 where
     M::Error: Send + 'static,
     M::Request: Send + RequestMetadata + 'static,
-    M::Response: Send + 'static,
+    M::Response: Send + ToString + 'static,
 {
     fn drop(&mut self) {
         self.stop();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct TestError(String);
+
+    impl std::fmt::Display for TestError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl std::error::Error for TestError {}
+
+    impl RequestMetadata for u32 {
+        type Metadata = u32;
+
+        fn metadata(&self) -> u32 {
+            *self
+        }
+    }
+
+    /// A model whose `run_batch` just doubles each request, recording the
+    /// batches it was called with so tests can inspect how the scheduler
+    /// coalesced them.
+    struct RecordingModel {
+        batches: Arc<Mutex<Vec<Vec<u32>>>>,
+    }
+
+    impl InfernumModel for RecordingModel {
+        type Request = u32;
+        type Response = u32;
+        type Error = TestError;
+
+        fn run(&mut self, request: u32) -> Result<u32, TestError> {
+            self.run_batch(vec![request]).pop().unwrap()
+        }
+
+        fn run_batch(&mut self, requests: Vec<u32>) -> Vec<Result<u32, TestError>> {
+            self.batches.lock().unwrap().push(requests.clone());
+            requests.into_iter().map(|n| Ok(n * 2)).collect()
+        }
+    }
+
+    fn await_result(engine: &InfernumEngine<RecordingModel>, id: JobId) -> u32 {
+        loop {
+            match engine.poll_by_id(id) {
+                InfernumEngineResult::Success(response) => return response.response,
+                InfernumEngineResult::Error(error) => panic!("job {id} failed: {error}"),
+                InfernumEngineResult::NotFound => panic!("job {id} vanished from the registry"),
+                InfernumEngineResult::Pending(_) => std::thread::sleep(Duration::from_millis(1)),
+            }
+        }
+    }
+
+    #[test]
+    fn run_batch_preserves_order_and_results() {
+        let batches = Arc::new(Mutex::new(Vec::new()));
+        let recorded = batches.clone();
+        let mut engine = InfernumEngine::with_batching(
+            move || {
+                Ok(RecordingModel {
+                    batches: recorded.clone(),
+                })
+            },
+            1,
+            DEFAULT_QUEUE_CAPACITY,
+            BatchConfig {
+                max_batch_size: 8,
+                max_wait: Duration::from_millis(20),
+            },
+        )
+        .unwrap();
+
+        let ids: Vec<JobId> = (0..8)
+            .map(|n| engine.schedule_inference(n).unwrap())
+            .collect();
+        let results: Vec<u32> = ids.iter().map(|&id| await_result(&engine, id)).collect();
+
+        assert_eq!(results, vec![0, 2, 4, 6, 8, 10, 12, 14]);
+        engine.stop();
+    }
+
+    #[test]
+    fn falls_back_to_a_singleton_batch_under_light_load() {
+        let batches = Arc::new(Mutex::new(Vec::new()));
+        let recorded = batches.clone();
+        let mut engine = InfernumEngine::with_batching(
+            move || {
+                Ok(RecordingModel {
+                    batches: recorded.clone(),
+                })
+            },
+            1,
+            DEFAULT_QUEUE_CAPACITY,
+            BatchConfig {
+                max_batch_size: 8,
+                max_wait: Duration::from_millis(20),
+            },
+        )
+        .unwrap();
+
+        let id = engine.schedule_inference(21).unwrap();
+        assert_eq!(await_result(&engine, id), 42);
+
+        assert_eq!(batches.lock().unwrap().as_slice(), [vec![21]]);
+        engine.stop();
+    }
+
+    #[test]
+    fn zero_max_wait_still_coalesces_already_queued_requests() {
+        let batches = Arc::new(Mutex::new(Vec::new()));
+        let recorded = batches.clone();
+        let mut engine = InfernumEngine::with_batching(
+            move || {
+                Ok(RecordingModel {
+                    batches: recorded.clone(),
+                })
+            },
+            1,
+            DEFAULT_QUEUE_CAPACITY,
+            BatchConfig {
+                max_batch_size: 4,
+                max_wait: Duration::ZERO,
+            },
+        )
+        .unwrap();
+
+        // Give the lone worker a chance to block on `recv()` before any
+        // requests exist, so the batch below is actually sitting in the
+        // channel together by the time the worker wakes up and starts
+        // draining it.
+        std::thread::sleep(Duration::from_millis(20));
+        let ids: Vec<JobId> = (0..4)
+            .map(|n| engine.schedule_inference(n).unwrap())
+            .collect();
+        for id in ids {
+            await_result(&engine, id);
+        }
+
+        assert!(
+            batches.lock().unwrap().iter().any(|batch| batch.len() > 1),
+            "expected at least one batch with more than one request, got {:?}",
+            batches.lock().unwrap()
+        );
+        engine.stop();
+    }
+}
@@ -0,0 +1,174 @@
+use axum::extract::ConnectInfo;
+use http::{Method, Request, Response};
+use std::{
+    future::Future,
+    net::SocketAddr,
+    pin::Pin,
+    task::{Context, Poll},
+    time::Instant,
+};
+use tower::{Layer, Service};
+use tracing::Instrument;
+use uuid::Uuid;
+
+/// Correlation id assigned to an inbound HTTP request by [`InfernumAccessLog`].
+///
+/// Stored as a request extension so handlers can pull it out (e.g. via
+/// `axum::Extension<RequestId>`) and pass it on to
+/// `InfernumEngine::schedule_inference_with_id`, keeping the HTTP request id
+/// and the inference job id in sync end-to-end.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RequestId(pub Uuid);
+
+/// `tower::Layer` that wraps a service with [`InfernumAccessLog`].
+///
+/// Requests can be logged or not depending on the `enabled` flag, so callers
+/// can wire this in unconditionally and toggle it via a CLI flag.
+#[derive(Clone, Debug)]
+pub struct InfernumAccessLogLayer {
+    enabled: bool,
+}
+
+impl InfernumAccessLogLayer {
+    /// Creates a new layer, logging requests only when `enabled` is `true`.
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+}
+
+impl<S> Layer<S> for InfernumAccessLogLayer {
+    type Service = InfernumAccessLog<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        InfernumAccessLog {
+            inner,
+            enabled: self.enabled,
+        }
+    }
+}
+
+/// Request-level access log: mints a [`RequestId`], opens a `tracing` span
+/// carrying the method, path, and peer address, and logs the outcome
+/// (status code and elapsed time) when the request completes, panics, or is
+/// dropped before completing.
+#[derive(Clone, Debug)]
+pub struct InfernumAccessLog<S> {
+    inner: S,
+    enabled: bool,
+}
+
+impl<S> InfernumAccessLog<S> {
+    /// Wraps `inner`, logging requests only when `enabled` is `true`.
+    pub fn new(inner: S, enabled: bool) -> Self {
+        Self { inner, enabled }
+    }
+}
+
+struct AccessLogGuard {
+    request_id: Uuid,
+    method: Method,
+    path: String,
+    peer: Option<SocketAddr>,
+    start: Instant,
+    completed: bool,
+}
+
+impl AccessLogGuard {
+    fn finish(mut self, status: Option<u16>) {
+        self.completed = true;
+        let elapsed = self.start.elapsed();
+        match status {
+            Some(status) => log::info!(
+                "[{}] {} {} from {:?} -> {status} in {elapsed:?}",
+                self.request_id,
+                self.method,
+                self.path,
+                self.peer
+            ),
+            None => log::error!(
+                "[{}] {} {} from {:?} failed after {elapsed:?}",
+                self.request_id,
+                self.method,
+                self.path,
+                self.peer
+            ),
+        }
+    }
+}
+
+impl Drop for AccessLogGuard {
+    fn drop(&mut self) {
+        if !self.completed {
+            log::error!(
+                "[{}] {} {} dropped or panicked after {:?}",
+                self.request_id,
+                self.method,
+                self.path,
+                self.start.elapsed()
+            );
+        }
+    }
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for InfernumAccessLog<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    ReqBody: Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request<ReqBody>) -> Self::Future {
+        let request_id = RequestId(Uuid::new_v4());
+        req.extensions_mut().insert(request_id);
+
+        if !self.enabled {
+            let future = self.inner.call(req);
+            return Box::pin(future);
+        }
+
+        let peer = req
+            .extensions()
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| *addr);
+        let guard = AccessLogGuard {
+            request_id: request_id.0,
+            method: req.method().clone(),
+            path: req.uri().path().to_string(),
+            peer,
+            start: Instant::now(),
+            completed: false,
+        };
+
+        let span = tracing::info_span!(
+            "request",
+            request_id = %request_id.0,
+            method = %guard.method,
+            path = %guard.path,
+            ?peer,
+        );
+
+        // Swap so we hold the clone that actually services this request,
+        // mirroring tower's usual "service must be ready before clone" dance.
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+
+        Box::pin(
+            async move {
+                let result = inner.call(req).await;
+                match &result {
+                    Ok(response) => guard.finish(Some(response.status().as_u16())),
+                    Err(_) => guard.finish(None),
+                }
+                result
+            }
+            .instrument(span),
+        )
+    }
+}